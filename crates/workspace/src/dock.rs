@@ -0,0 +1,86 @@
+use gpui::{Action, AnyView, App, FocusHandle, Focusable, SharedString, Window, actions};
+use std::sync::Arc;
+use ui::IconName;
+
+actions!(workspace, [ToggleDockPanel]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockPosition {
+    Left,
+    Right,
+    Bottom,
+}
+
+impl DockPosition {
+    pub fn label(&self) -> &'static str {
+        match self {
+            DockPosition::Left => "Left",
+            DockPosition::Right => "Right",
+            DockPosition::Bottom => "Bottom",
+        }
+    }
+}
+
+/// A panel that can be docked to one side of the workspace and toggled from the
+/// sidebar rail. Implemented by each concrete panel (project, outline, terminal, ...).
+pub trait PanelHandle: Send + Sync {
+    fn persistent_name(&self) -> &'static str;
+    fn position_is_valid(&self, position: DockPosition, cx: &App) -> bool;
+    fn set_position(&self, position: DockPosition, window: &mut Window, cx: &mut App);
+    fn icon(&self, window: &mut Window, cx: &App) -> Option<IconName>;
+    fn icon_tooltip(&self, window: &mut Window, cx: &App) -> Option<SharedString>;
+    fn toggle_action(&self, window: &mut Window, cx: &App) -> Box<dyn Action>;
+
+    /// A small live preview to show in a hover popover in place of the default tooltip,
+    /// e.g. the next few outline entries or a terminal's pending output. Panels with
+    /// nothing meaningful to preview keep the default (no popover, just the tooltip).
+    fn hover_preview(&self, _window: &mut Window, _cx: &App) -> Option<AnyView> {
+        None
+    }
+}
+
+pub struct Dock {
+    position: DockPosition,
+    is_open: bool,
+    panels: Vec<Arc<dyn PanelHandle>>,
+    active_panel_index: Option<usize>,
+    focus_handle: FocusHandle,
+}
+
+impl Dock {
+    pub fn new(position: DockPosition, cx: &mut App) -> Self {
+        Self {
+            position,
+            is_open: false,
+            panels: Vec::new(),
+            active_panel_index: None,
+            focus_handle: cx.focus_handle(),
+        }
+    }
+
+    pub fn position(&self) -> DockPosition {
+        self.position
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    pub fn active_panel_index(&self) -> Option<usize> {
+        self.active_panel_index
+    }
+
+    pub fn panels(&self) -> impl Iterator<Item = &Arc<dyn PanelHandle>> {
+        self.panels.iter()
+    }
+
+    pub fn toggle_action(&self) -> Box<dyn Action> {
+        Box::new(ToggleDockPanel)
+    }
+}
+
+impl Focusable for Dock {
+    fn focus_handle(&self, _cx: &App) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
@@ -0,0 +1,10 @@
+pub mod dock;
+pub mod sidebar;
+
+use gpui::App;
+
+/// Registers this crate's settings with the global `SettingsStore`. Should run once
+/// during workspace startup, before any sidebar or dock UI is rendered.
+pub fn init(cx: &mut App) {
+    sidebar::init(cx);
+}
@@ -1,14 +1,141 @@
 use crate::dock::{Dock, DockPosition, PanelHandle};
+use anyhow::Result;
+use fs::Fs;
 use gpui::{
-    Action, AnyElement, AnyView, App, Context, Corner, Entity, FocusHandle, Focusable, IntoElement,
-    ParentElement, Render, Styled, Subscription, Window,
+    Action, AnyElement, AnyView, App, Context, Corner, Entity, FocusHandle, Focusable,
+    InteractiveElement, IntoElement, ParentElement, Render, SharedString, Styled, Subscription,
+    WeakEntity, Window, anchored, deferred, div,
 };
-use settings::SettingsStore;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use settings::{Settings, SettingsSources, SettingsStore, update_settings_file};
 use std::sync::Arc;
 use ui::{
-    ContextMenu, Divider, DividerColor, IconButton, IconSize, Tooltip, prelude::*,
+    ContextMenu, Divider, DividerColor, IconButton, IconName, IconSize, Tooltip, prelude::*,
     right_click_menu, v_flex,
 };
+use util::ResultExt;
+
+/// Per-side, per-group panel ordering for the sidebar rail, configured via the
+/// `sidebar` settings key. Lists are persistent panel names (e.g. `"GitPanel"`);
+/// a side/group that is left unset keeps the compiled-in default order.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SidebarSettings {
+    pub left: SidebarGroupSettings,
+    pub right: SidebarGroupSettings,
+}
+
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SidebarGroupSettings {
+    pub top: Option<Vec<SharedString>>,
+    pub bottom: Option<Vec<SharedString>>,
+    pub custom_buttons: SidebarCustomButtonsSettings,
+}
+
+/// User-configured custom buttons that dispatch an arbitrary registered
+/// `Action`, keyed by which group of the rail they should render in.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct SidebarCustomButtonsSettings {
+    pub top: Vec<CustomSidebarButtonSettings>,
+    pub bottom: Vec<CustomSidebarButtonSettings>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CustomSidebarButtonSettings {
+    pub icon: IconName,
+    pub tooltip: SharedString,
+    pub action_name: SharedString,
+    pub action_arguments: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, JsonSchema, Debug)]
+pub struct SidebarSettingsContent {
+    pub left: Option<SidebarGroupSettingsContent>,
+    pub right: Option<SidebarGroupSettingsContent>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, JsonSchema, Debug)]
+pub struct SidebarGroupSettingsContent {
+    pub top: Option<Vec<String>>,
+    pub bottom: Option<Vec<String>>,
+    pub custom_buttons: Option<SidebarCustomButtonsContent>,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize, JsonSchema, Debug)]
+pub struct SidebarCustomButtonsContent {
+    pub top: Option<Vec<CustomSidebarButtonContent>>,
+    pub bottom: Option<Vec<CustomSidebarButtonContent>>,
+}
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema, Debug)]
+pub struct CustomSidebarButtonContent {
+    pub icon: IconName,
+    pub tooltip: String,
+    pub action: String,
+    #[serde(default)]
+    pub action_arguments: Option<serde_json::Value>,
+}
+
+impl From<SidebarGroupSettingsContent> for SidebarGroupSettings {
+    fn from(content: SidebarGroupSettingsContent) -> Self {
+        Self {
+            top: content
+                .top
+                .map(|names| names.into_iter().map(SharedString::from).collect()),
+            bottom: content
+                .bottom
+                .map(|names| names.into_iter().map(SharedString::from).collect()),
+            custom_buttons: content.custom_buttons.map(Into::into).unwrap_or_default(),
+        }
+    }
+}
+
+impl From<SidebarCustomButtonsContent> for SidebarCustomButtonsSettings {
+    fn from(content: SidebarCustomButtonsContent) -> Self {
+        Self {
+            top: content
+                .top
+                .map(|buttons| buttons.into_iter().map(Into::into).collect())
+                .unwrap_or_default(),
+            bottom: content
+                .bottom
+                .map(|buttons| buttons.into_iter().map(Into::into).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl From<CustomSidebarButtonContent> for CustomSidebarButtonSettings {
+    fn from(content: CustomSidebarButtonContent) -> Self {
+        Self {
+            icon: content.icon,
+            tooltip: content.tooltip.into(),
+            action_name: content.action.into(),
+            action_arguments: content.action_arguments,
+        }
+    }
+}
+
+impl Settings for SidebarSettings {
+    const KEY: Option<&'static str> = Some("sidebar");
+
+    type FileContent = SidebarSettingsContent;
+
+    fn load(sources: SettingsSources<Self::FileContent>, _cx: &mut App) -> Result<Self> {
+        let content = sources.json_merge::<SidebarSettingsContent>()?;
+        Ok(Self {
+            left: content.left.map(Into::into).unwrap_or_default(),
+            right: content.right.map(Into::into).unwrap_or_default(),
+        })
+    }
+}
+
+/// Registers the `sidebar` settings key with the global `SettingsStore`. Must run
+/// before any `SidebarButtons` is rendered, since `group_settings` reads it via
+/// `SidebarSettings::get_global`.
+pub fn init(cx: &mut App) {
+    SidebarSettings::register(cx);
+}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SidebarSide {
@@ -16,12 +143,160 @@ pub enum SidebarSide {
     Right,
 }
 
+/// Which group of the rail a custom button (see [`SidebarButtons::add_custom_action_button`])
+/// renders in, matching the top/bottom split used for panel buttons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SidebarButtonGroup {
+    Top,
+    Bottom,
+}
+
+/// Resolves a group's panel order: the user's configured list if present, otherwise
+/// the side's compiled-in default order. Pulled out as a pure function so the
+/// fallback logic can be unit tested without a `SettingsStore`.
+fn resolve_panel_order(
+    configured: Option<Vec<SharedString>>,
+    defaults: impl FnOnce() -> Vec<SharedString>,
+) -> Vec<SharedString> {
+    configured.unwrap_or_else(defaults)
+}
+
+/// Finds which group (top/bottom) a panel belongs to and its index within that
+/// group's order, by persistent name. Returns `None` for a panel that isn't listed
+/// in either group, which `collect_buttons_from_dock` skips.
+fn panel_group_and_index(
+    name: &str,
+    top_names: &[SharedString],
+    bottom_names: &[SharedString],
+) -> Option<(SidebarButtonGroup, usize)> {
+    if let Some(index) = top_names.iter().position(|n| n.as_ref() == name) {
+        return Some((SidebarButtonGroup::Top, index));
+    }
+    bottom_names
+        .iter()
+        .position(|n| n.as_ref() == name)
+        .map(|index| (SidebarButtonGroup::Bottom, index))
+}
+
+/// Whether a drop is a button dropped onto its own current position: same group,
+/// same name. Callers should treat this as a no-op rather than reordering.
+fn is_self_drop(
+    dragged_group: SidebarButtonGroup,
+    dragged_name: &SharedString,
+    target_group: SidebarButtonGroup,
+    target_name: &SharedString,
+) -> bool {
+    dragged_group == target_group && dragged_name == target_name
+}
+
+/// Computes the new order for a group after dragging `dragged_name` onto
+/// `target_name` within that group. `order` may or may not already contain
+/// `dragged_name` (it won't if this is a cross-group move), so it's removed first
+/// if present, then reinserted at `target_name`'s position (or the end, if
+/// `target_name` isn't found).
+fn reorder_panel_names(
+    mut order: Vec<SharedString>,
+    dragged_name: &SharedString,
+    target_name: &SharedString,
+) -> Vec<SharedString> {
+    order.retain(|name| name != dragged_name);
+    let target_index = order
+        .iter()
+        .position(|name| name == target_name)
+        .unwrap_or(order.len());
+    order.insert(target_index, dragged_name.clone());
+    order
+}
+
+/// Moves `name` from `source_order` to the end of `target_order`, used when a
+/// panel button is dropped on the divider between the top/bottom groups.
+fn move_panel_to_group_end(
+    mut source_order: Vec<SharedString>,
+    mut target_order: Vec<SharedString>,
+    name: &SharedString,
+) -> (Vec<SharedString>, Vec<SharedString>) {
+    source_order.retain(|n| n != name);
+    target_order.push(name.clone());
+    (source_order, target_order)
+}
+
+/// The compiled-in default panel order for `side`'s `group`, used when the user
+/// hasn't configured that side/group. A free function (rather than a method) so a
+/// cross-side drag can look up the *other* side's defaults too.
+fn default_panel_names(side: SidebarSide, group: SidebarButtonGroup) -> Vec<SharedString> {
+    match (side, group) {
+        (SidebarSide::Left, SidebarButtonGroup::Top) => {
+            ["Project Panel", "GitPanel", "Outline Panel", "CollabPanel"]
+                .map(SharedString::new_static)
+                .to_vec()
+        }
+        (SidebarSide::Left, SidebarButtonGroup::Bottom) => ["TerminalPanel", "DebugPanel"]
+            .map(SharedString::new_static)
+            .to_vec(),
+        (SidebarSide::Right, SidebarButtonGroup::Top) => {
+            ["AgentPanel", "AgentsPanel", "NotificationPanel"]
+                .map(SharedString::new_static)
+                .to_vec()
+        }
+        (SidebarSide::Right, SidebarButtonGroup::Bottom) => Vec::new(),
+    }
+}
+
+/// `side`'s settings, independent of which `SidebarButtons` instance is asking.
+fn group_settings_for(side: SidebarSide, cx: &App) -> &SidebarGroupSettings {
+    let settings = SidebarSettings::get_global(cx);
+    match side {
+        SidebarSide::Left => &settings.left,
+        SidebarSide::Right => &settings.right,
+    }
+}
+
+/// Resolves `side`'s configured (or default) panel order for `group`. Used both for
+/// `self.side` (via the `get_top_panel_names`/`get_bottom_panel_names` methods) and,
+/// on a cross-side drag, to read the *other* `SidebarButtons`' current order without
+/// needing a reference to that instance.
+fn panel_names_for(side: SidebarSide, group: SidebarButtonGroup, cx: &App) -> Vec<SharedString> {
+    let group_settings = group_settings_for(side, cx);
+    let configured = match group {
+        SidebarButtonGroup::Top => group_settings.top.clone(),
+        SidebarButtonGroup::Bottom => group_settings.bottom.clone(),
+    };
+    resolve_panel_order(configured, || default_panel_names(side, group))
+}
+
+struct CustomSidebarButton {
+    icon: IconName,
+    tooltip: SharedString,
+    action: Box<dyn Action>,
+    group: SidebarButtonGroup,
+}
+
+/// Drag payload for reordering panel buttons within the rail: which panel is being
+/// dragged, where it currently lives, and enough to render a drag preview.
+#[derive(Clone)]
+struct DraggedSidebarButton {
+    panel_name: SharedString,
+    icon: IconName,
+    side: SidebarSide,
+    group: SidebarButtonGroup,
+    panel: Arc<dyn PanelHandle>,
+}
+
+impl Render for DraggedSidebarButton {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        IconButton::new("dragged-sidebar-button", self.icon).icon_size(IconSize::Medium)
+    }
+}
+
 pub struct SidebarButtons {
     side: SidebarSide,
     left_dock: Entity<Dock>,
     bottom_dock: Entity<Dock>,
     right_dock: Entity<Dock>,
     bottom_items: Vec<AnyView>,
+    custom_buttons: Vec<CustomSidebarButton>,
+    hovered_panel: Option<SharedString>,
+    fs: Arc<dyn Fs>,
     _subscriptions: Vec<Subscription>,
 }
 
@@ -31,6 +306,7 @@ impl SidebarButtons {
         left_dock: Entity<Dock>,
         bottom_dock: Entity<Dock>,
         right_dock: Entity<Dock>,
+        fs: Arc<dyn Fs>,
         cx: &mut Context<Self>,
     ) -> Self {
         let subscriptions = vec![
@@ -45,6 +321,9 @@ impl SidebarButtons {
             bottom_dock,
             right_dock,
             bottom_items: Vec::new(),
+            custom_buttons: Vec::new(),
+            hovered_panel: None,
+            fs,
             _subscriptions: subscriptions,
         }
     }
@@ -54,18 +333,221 @@ impl SidebarButtons {
         cx.notify();
     }
 
-    fn get_top_panel_names(&self) -> &[&'static str] {
-        match self.side {
-            SidebarSide::Left => &["Project Panel", "GitPanel", "Outline Panel", "CollabPanel"],
-            SidebarSide::Right => &["AgentPanel", "AgentsPanel", "NotificationPanel"],
+    /// Pins a custom button that dispatches `action` when clicked, alongside the panel
+    /// buttons in `group`. Lets power users add one-off actions (e.g. toggling zen mode,
+    /// running a task) straight onto the rail without a panel backing them.
+    pub fn add_custom_action_button(
+        &mut self,
+        icon: IconName,
+        tooltip: impl Into<SharedString>,
+        action: Box<dyn Action>,
+        group: SidebarButtonGroup,
+        cx: &mut Context<Self>,
+    ) {
+        self.custom_buttons.push(CustomSidebarButton {
+            icon,
+            tooltip: tooltip.into(),
+            action,
+            group,
+        });
+        cx.notify();
+    }
+
+    fn default_top_panel_names(&self) -> Vec<SharedString> {
+        default_panel_names(self.side, SidebarButtonGroup::Top)
+    }
+
+    fn default_bottom_panel_names(&self) -> Vec<SharedString> {
+        default_panel_names(self.side, SidebarButtonGroup::Bottom)
+    }
+
+    fn group_settings(&self, cx: &App) -> &SidebarGroupSettings {
+        group_settings_for(self.side, cx)
+    }
+
+    fn get_top_panel_names(&self, cx: &App) -> Vec<SharedString> {
+        panel_names_for(self.side, SidebarButtonGroup::Top, cx)
+    }
+
+    fn get_bottom_panel_names(&self, cx: &App) -> Vec<SharedString> {
+        panel_names_for(self.side, SidebarButtonGroup::Bottom, cx)
+    }
+
+    /// Writes a new panel order for `group` on this side into the user's settings
+    /// file, the same `sidebar.<side>.<group>` list that `get_top_panel_names`/
+    /// `get_bottom_panel_names` read back on the next render.
+    fn persist_panel_order(
+        &self,
+        group: SidebarButtonGroup,
+        order: Vec<SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        self.persist_panel_orders(vec![(self.side, group, order)], cx);
+    }
+
+    /// Writes panel orders for one or more side/group combinations into the user's
+    /// settings file in a single read-modify-write. Drags that touch two lists at
+    /// once (moving a panel between groups or between sides) must go through this
+    /// rather than two back-to-back `persist_panel_order` calls: each call is its
+    /// own async round trip against the settings file, so the second could read the
+    /// file before the first's write lands and silently overwrite it.
+    fn persist_panel_orders(
+        &self,
+        updates: Vec<(SidebarSide, SidebarButtonGroup, Vec<SharedString>)>,
+        cx: &mut Context<Self>,
+    ) {
+        let updates: Vec<(SidebarSide, SidebarButtonGroup, Vec<String>)> = updates
+            .into_iter()
+            .map(|(side, group, order)| {
+                let names = order.into_iter().map(|name| name.to_string()).collect();
+                (side, group, names)
+            })
+            .collect();
+        update_settings_file::<SidebarSettings>(self.fs.clone(), cx, move |content, _cx| {
+            for (side, group, names) in updates {
+                let side_content = match side {
+                    SidebarSide::Left => content.left.get_or_insert_with(Default::default),
+                    SidebarSide::Right => content.right.get_or_insert_with(Default::default),
+                };
+                match group {
+                    SidebarButtonGroup::Top => side_content.top = Some(names),
+                    SidebarButtonGroup::Bottom => side_content.bottom = Some(names),
+                }
+            }
+        });
+    }
+
+    /// Handles a button dropped onto `target_name`: reordering within the same
+    /// side+group, moving between this side's top/bottom groups, or (for a drop
+    /// coming from the other `SidebarButtons`) docking the panel on this side.
+    fn handle_panel_button_drop(
+        &mut self,
+        dragged: &DraggedSidebarButton,
+        target_name: SharedString,
+        target_group: SidebarButtonGroup,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if dragged.side != self.side {
+            let position = match self.side {
+                SidebarSide::Left => DockPosition::Left,
+                SidebarSide::Right => DockPosition::Right,
+            };
+            dragged.panel.set_position(position, window, cx);
+
+            let mut source_order = panel_names_for(dragged.side, dragged.group, cx);
+            source_order.retain(|name| name != &dragged.panel_name);
+
+            let target_order = panel_names_for(self.side, target_group, cx);
+            let target_order = reorder_panel_names(target_order, &dragged.panel_name, &target_name);
+
+            self.persist_panel_orders(
+                vec![
+                    (dragged.side, dragged.group, source_order),
+                    (self.side, target_group, target_order),
+                ],
+                cx,
+            );
+            return;
+        }
+
+        if is_self_drop(
+            dragged.group,
+            &dragged.panel_name,
+            target_group,
+            &target_name,
+        ) {
+            return;
+        }
+
+        let order = if target_group == SidebarButtonGroup::Top {
+            self.get_top_panel_names(cx)
+        } else {
+            self.get_bottom_panel_names(cx)
+        };
+
+        if dragged.group != target_group {
+            let mut other_order = if dragged.group == SidebarButtonGroup::Top {
+                self.get_top_panel_names(cx)
+            } else {
+                self.get_bottom_panel_names(cx)
+            };
+            other_order.retain(|name| name != &dragged.panel_name);
+            let order = reorder_panel_names(order, &dragged.panel_name, &target_name);
+            self.persist_panel_orders(
+                vec![
+                    (self.side, dragged.group, other_order),
+                    (self.side, target_group, order),
+                ],
+                cx,
+            );
+            return;
         }
+
+        let order = reorder_panel_names(order, &dragged.panel_name, &target_name);
+        self.persist_panel_order(target_group, order, cx);
     }
 
-    fn get_bottom_panel_names(&self) -> &[&'static str] {
-        match self.side {
-            SidebarSide::Left => &["TerminalPanel", "DebugPanel"],
-            SidebarSide::Right => &[],
+    /// Handles a button dropped on the `Divider` between groups: moves it to the
+    /// opposite group on this side, appending it at the end. A button dragged from
+    /// the other `SidebarButtons` is instead docked on this side and appended to the
+    /// end of the same group (top/bottom) it had on its original side.
+    fn handle_group_divider_drop(
+        &mut self,
+        dragged: &DraggedSidebarButton,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        if dragged.side != self.side {
+            let position = match self.side {
+                SidebarSide::Left => DockPosition::Left,
+                SidebarSide::Right => DockPosition::Right,
+            };
+            dragged.panel.set_position(position, window, cx);
+
+            // No specific target group to switch to on the new side (there's no
+            // divider-adjacent group here), so keep the panel in the same group
+            // (top/bottom) it had on its original side.
+            let source_order = panel_names_for(dragged.side, dragged.group, cx);
+            let target_order = panel_names_for(self.side, dragged.group, cx);
+            let (source_order, target_order) =
+                move_panel_to_group_end(source_order, target_order, &dragged.panel_name);
+
+            self.persist_panel_orders(
+                vec![
+                    (dragged.side, dragged.group, source_order),
+                    (self.side, dragged.group, target_order),
+                ],
+                cx,
+            );
+            return;
         }
+
+        let target_group = match dragged.group {
+            SidebarButtonGroup::Top => SidebarButtonGroup::Bottom,
+            SidebarButtonGroup::Bottom => SidebarButtonGroup::Top,
+        };
+
+        let source_order = if dragged.group == SidebarButtonGroup::Top {
+            self.get_top_panel_names(cx)
+        } else {
+            self.get_bottom_panel_names(cx)
+        };
+        let target_order = if target_group == SidebarButtonGroup::Top {
+            self.get_top_panel_names(cx)
+        } else {
+            self.get_bottom_panel_names(cx)
+        };
+
+        let (source_order, target_order) =
+            move_panel_to_group_end(source_order, target_order, &dragged.panel_name);
+        self.persist_panel_orders(
+            vec![
+                (self.side, dragged.group, source_order),
+                (self.side, target_group, target_order),
+            ],
+            cx,
+        );
     }
 
     fn render_panel_button(
@@ -73,8 +555,10 @@ impl SidebarButtons {
         panel: &Arc<dyn PanelHandle>,
         is_active_button: bool,
         dock_position: DockPosition,
+        group: SidebarButtonGroup,
         toggle_action: Box<dyn Action>,
         focus_handle: FocusHandle,
+        weak_entity: WeakEntity<Self>,
         window: &mut Window,
         cx: &App,
     ) -> Option<impl IntoElement> {
@@ -82,6 +566,10 @@ impl SidebarButtons {
         let icon_tooltip = panel.icon_tooltip(window, cx)?;
         let name = panel.persistent_name();
         let panel_clone = panel.clone();
+        let hover_preview = (!is_active_button)
+            .then(|| panel.hover_preview(window, cx))
+            .flatten();
+        let is_hovered = self.hovered_panel.as_deref() == Some(name);
 
         let (action, tooltip): (Box<dyn Action>, SharedString) = if is_active_button {
             (
@@ -97,54 +585,110 @@ impl SidebarButtons {
             SidebarSide::Right => (Corner::TopRight, Corner::TopLeft),
         };
 
-        Some(
-            right_click_menu(name)
-                .menu(move |window, cx| {
-                    const POSITIONS: [DockPosition; 3] = [
-                        DockPosition::Left,
-                        DockPosition::Right,
-                        DockPosition::Bottom,
-                    ];
-
-                    ContextMenu::build(window, cx, |mut menu, _, cx| {
-                        for position in POSITIONS {
-                            if position != dock_position
-                                && panel_clone.position_is_valid(position, cx)
-                            {
-                                let panel = panel_clone.clone();
-                                menu = menu.entry(
-                                    format!("Dock {}", position.label()),
-                                    None,
-                                    move |window, cx| {
-                                        panel.set_position(position, window, cx);
-                                    },
-                                )
-                            }
+        let show_tooltip = hover_preview.is_none();
+
+        let trigger = right_click_menu(name)
+            .menu(move |window, cx| {
+                const POSITIONS: [DockPosition; 3] = [
+                    DockPosition::Left,
+                    DockPosition::Right,
+                    DockPosition::Bottom,
+                ];
+
+                ContextMenu::build(window, cx, |mut menu, _, cx| {
+                    for position in POSITIONS {
+                        if position != dock_position && panel_clone.position_is_valid(position, cx)
+                        {
+                            let panel = panel_clone.clone();
+                            menu = menu.entry(
+                                format!("Dock {}", position.label()),
+                                None,
+                                move |window, cx| {
+                                    panel.set_position(position, window, cx);
+                                },
+                            )
                         }
-                        menu
-                    })
+                    }
+                    menu
                 })
-                .anchor(menu_anchor)
-                .attach(menu_attach)
-                .trigger(move |is_active, _window, _cx| {
-                    IconButton::new((name, is_active_button as u64), icon)
-                        .icon_size(IconSize::Medium)
-                        .toggle_state(is_active_button)
-                        .on_click({
-                            let action = action.boxed_clone();
-                            let focus_handle = focus_handle.clone();
-                            move |_, window, cx| {
-                                window.focus(&focus_handle, cx);
-                                window.dispatch_action(action.boxed_clone(), cx)
-                            }
+            })
+            .anchor(menu_anchor)
+            .attach(menu_attach)
+            .trigger(move |is_active, _window, _cx| {
+                IconButton::new((name, is_active_button as u64), icon)
+                    .icon_size(IconSize::Medium)
+                    .toggle_state(is_active_button)
+                    .on_click({
+                        let action = action.boxed_clone();
+                        let focus_handle = focus_handle.clone();
+                        move |_, window, cx| {
+                            window.focus(&focus_handle, cx);
+                            window.dispatch_action(action.boxed_clone(), cx)
+                        }
+                    })
+                    .when(!is_active && show_tooltip, |this| {
+                        let tooltip = tooltip.clone();
+                        let action = action.boxed_clone();
+                        this.tooltip(move |_window, cx| {
+                            Tooltip::for_action(tooltip.clone(), &*action, cx)
                         })
-                        .when(!is_active, |this| {
-                            let tooltip = tooltip.clone();
-                            let action = action.boxed_clone();
-                            this.tooltip(move |_window, cx| {
-                                Tooltip::for_action(tooltip.clone(), &*action, cx)
-                            })
+                    })
+            });
+
+        let name = SharedString::from(name);
+        let side = self.side;
+        let dragged_button = DraggedSidebarButton {
+            panel_name: name.clone(),
+            icon: panel.icon(window, cx)?,
+            side,
+            group,
+            panel: panel.clone(),
+        };
+
+        let hover_weak_entity = weak_entity.clone();
+        let hover_name = name.clone();
+        let drop_weak_entity = weak_entity.clone();
+        let drop_name = name.clone();
+
+        Some(
+            div()
+                .relative()
+                .on_hover(move |hovered, _window, cx| {
+                    let hover_name = hover_name.clone();
+                    hover_weak_entity
+                        .update(cx, |this, cx| {
+                            this.hovered_panel = hovered.then_some(hover_name);
+                            cx.notify();
+                        })
+                        .ok();
+                })
+                .on_drag(dragged_button, |dragged, _, _, cx| {
+                    cx.new(|_| dragged.clone())
+                })
+                .drag_over::<DraggedSidebarButton>(|style, _, _, cx| {
+                    style.bg(cx.theme().colors().drop_target_background)
+                })
+                .on_drop(move |dragged: &DraggedSidebarButton, window, cx| {
+                    let drop_name = drop_name.clone();
+                    drop_weak_entity
+                        .update(cx, |this, cx| {
+                            this.handle_panel_button_drop(dragged, drop_name, group, window, cx);
                         })
+                        .ok();
+                })
+                .child(trigger)
+                .when(is_hovered, |this| {
+                    this.when_some(hover_preview, |this, preview| {
+                        this.child(
+                            deferred(
+                                anchored()
+                                    .anchor(menu_anchor)
+                                    .attach(menu_attach)
+                                    .child(div().occlude().child(preview)),
+                            )
+                            .with_priority(1),
+                        )
+                    })
                 }),
         )
     }
@@ -152,10 +696,10 @@ impl SidebarButtons {
     fn collect_buttons_from_dock(
         &self,
         dock: &Entity<Dock>,
-        top_names: &[&'static str],
-        bottom_names: &[&'static str],
-        top_buttons: &mut Vec<AnyElement>,
-        bottom_buttons: &mut Vec<AnyElement>,
+        top_names: &[SharedString],
+        bottom_names: &[SharedString],
+        top_buttons: &mut Vec<(usize, AnyElement)>,
+        bottom_buttons: &mut Vec<(usize, AnyElement)>,
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
@@ -165,44 +709,112 @@ impl SidebarButtons {
         let active_panel_index = dock_read.active_panel_index();
         let toggle_action = dock_read.toggle_action();
         let focus_handle = dock_read.focus_handle(cx);
+        let weak_entity = cx.weak_entity();
 
         for (i, panel) in dock_read.panels().enumerate() {
             let name = panel.persistent_name();
             let is_active_button = Some(i) == active_panel_index && is_open;
 
-            let should_show_in_top = top_names.contains(&name);
-            let should_show_in_bottom = bottom_names.contains(&name);
-
-            if !should_show_in_top && !should_show_in_bottom {
+            let Some((group, index)) = panel_group_and_index(name, top_names, bottom_names) else {
                 continue;
-            }
+            };
 
             if let Some(button) = self.render_panel_button(
                 panel,
                 is_active_button,
                 dock_position,
+                group,
                 toggle_action.boxed_clone(),
                 focus_handle.clone(),
+                weak_entity.clone(),
                 window,
                 cx,
             ) {
-                if should_show_in_top {
-                    top_buttons.push(button.into_any_element());
-                } else if should_show_in_bottom {
-                    bottom_buttons.push(button.into_any_element());
+                match group {
+                    SidebarButtonGroup::Top => top_buttons.push((index, button.into_any_element())),
+                    SidebarButtonGroup::Bottom => {
+                        bottom_buttons.push((index, button.into_any_element()))
+                    }
                 }
             }
         }
     }
+
+    fn render_custom_button(
+        &self,
+        key: u64,
+        icon: IconName,
+        tooltip: SharedString,
+        action: Box<dyn Action>,
+    ) -> impl IntoElement {
+        IconButton::new(("custom-sidebar-button", key), icon)
+            .icon_size(IconSize::Medium)
+            .on_click({
+                let action = action.boxed_clone();
+                move |_, window, cx| window.dispatch_action(action.boxed_clone(), cx)
+            })
+            .tooltip(move |_window, cx| Tooltip::for_action(tooltip.clone(), &*action, cx))
+    }
+
+    fn collect_custom_buttons(
+        &self,
+        top_buttons: &mut Vec<AnyElement>,
+        bottom_buttons: &mut Vec<AnyElement>,
+        cx: &App,
+    ) {
+        let mut key = 0;
+        let configured = &self.group_settings(cx).custom_buttons;
+
+        for button in &configured.top {
+            if let Some(action) = cx
+                .build_action(&button.action_name, button.action_arguments.clone())
+                .log_err()
+            {
+                top_buttons.push(
+                    self.render_custom_button(key, button.icon, button.tooltip.clone(), action)
+                        .into_any_element(),
+                );
+            }
+            key += 1;
+        }
+        for button in &configured.bottom {
+            if let Some(action) = cx
+                .build_action(&button.action_name, button.action_arguments.clone())
+                .log_err()
+            {
+                bottom_buttons.push(
+                    self.render_custom_button(key, button.icon, button.tooltip.clone(), action)
+                        .into_any_element(),
+                );
+            }
+            key += 1;
+        }
+
+        for button in &self.custom_buttons {
+            let element = self
+                .render_custom_button(
+                    key,
+                    button.icon,
+                    button.tooltip.clone(),
+                    button.action.boxed_clone(),
+                )
+                .into_any_element();
+            match button.group {
+                SidebarButtonGroup::Top => top_buttons.push(element),
+                SidebarButtonGroup::Bottom => bottom_buttons.push(element),
+            }
+            key += 1;
+        }
+    }
 }
 
 impl Render for SidebarButtons {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        let top_names = self.get_top_panel_names();
-        let bottom_names = self.get_bottom_panel_names();
+        let top_names = self.get_top_panel_names(cx);
+        let bottom_names = self.get_bottom_panel_names(cx);
 
-        let mut top_buttons: Vec<AnyElement> = Vec::new();
-        let mut bottom_buttons: Vec<AnyElement> = Vec::new();
+        let mut top_buttons: Vec<(usize, AnyElement)> = Vec::new();
+        let mut bottom_buttons: Vec<(usize, AnyElement)> = Vec::new();
 
         let left_dock = self.left_dock.clone();
         let bottom_dock = self.bottom_dock.clone();
@@ -210,8 +822,8 @@ impl Render for SidebarButtons {
 
         self.collect_buttons_from_dock(
             &left_dock,
-            top_names,
-            bottom_names,
+            &top_names,
+            &bottom_names,
             &mut top_buttons,
             &mut bottom_buttons,
             window,
@@ -219,8 +831,8 @@ impl Render for SidebarButtons {
         );
         self.collect_buttons_from_dock(
             &bottom_dock,
-            top_names,
-            bottom_names,
+            &top_names,
+            &bottom_names,
             &mut top_buttons,
             &mut bottom_buttons,
             window,
@@ -228,14 +840,26 @@ impl Render for SidebarButtons {
         );
         self.collect_buttons_from_dock(
             &right_dock,
-            top_names,
-            bottom_names,
+            &top_names,
+            &bottom_names,
             &mut top_buttons,
             &mut bottom_buttons,
             window,
             cx,
         );
 
+        top_buttons.sort_by_key(|(index, _)| *index);
+        bottom_buttons.sort_by_key(|(index, _)| *index);
+
+        let mut top_buttons: Vec<AnyElement> =
+            top_buttons.into_iter().map(|(_, button)| button).collect();
+        let mut bottom_buttons: Vec<AnyElement> = bottom_buttons
+            .into_iter()
+            .map(|(_, button)| button)
+            .collect();
+
+        self.collect_custom_buttons(&mut top_buttons, &mut bottom_buttons, cx);
+
         for item in &self.bottom_items {
             bottom_buttons.push(item.clone().into_any_element());
         }
@@ -243,6 +867,8 @@ impl Render for SidebarButtons {
         let has_top_buttons = !top_buttons.is_empty();
         let has_bottom_buttons = !bottom_buttons.is_empty();
 
+        let divider_weak_entity = cx.weak_entity();
+
         v_flex()
             .h_full()
             .justify_between()
@@ -251,8 +877,154 @@ impl Render for SidebarButtons {
             .px_0p5()
             .child(v_flex().gap_2().children(top_buttons))
             .when(has_top_buttons && has_bottom_buttons, |this| {
-                this.child(Divider::horizontal().color(DividerColor::Border))
+                this.child(
+                    div()
+                        .drag_over::<DraggedSidebarButton>(|style, _, _, cx| {
+                            style.bg(cx.theme().colors().drop_target_background)
+                        })
+                        .on_drop(move |dragged: &DraggedSidebarButton, window, cx| {
+                            divider_weak_entity
+                                .update(cx, |this, cx| {
+                                    this.handle_group_divider_drop(dragged, window, cx);
+                                })
+                                .ok();
+                        })
+                        .child(Divider::horizontal().color(DividerColor::Border)),
+                )
             })
             .child(v_flex().gap_2().children(bottom_buttons))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<SharedString> {
+        names.iter().map(|name| SharedString::from(*name)).collect()
+    }
+
+    #[test]
+    fn default_panel_names_differ_by_side_and_group() {
+        let left_top = default_panel_names(SidebarSide::Left, SidebarButtonGroup::Top);
+        let right_top = default_panel_names(SidebarSide::Right, SidebarButtonGroup::Top);
+        let left_bottom = default_panel_names(SidebarSide::Left, SidebarButtonGroup::Bottom);
+        let right_bottom = default_panel_names(SidebarSide::Right, SidebarButtonGroup::Bottom);
+
+        assert_ne!(left_top, right_top);
+        assert_ne!(left_bottom, right_bottom);
+        assert!(right_bottom.is_empty());
+    }
+
+    #[test]
+    fn resolve_panel_order_uses_configured_list_when_present() {
+        let configured = Some(names(&["GitPanel", "Project Panel"]));
+        let resolved = resolve_panel_order(configured.clone(), || names(&["Fallback"]));
+        assert_eq!(resolved, configured.unwrap());
+    }
+
+    #[test]
+    fn resolve_panel_order_falls_back_to_defaults_when_unset() {
+        let resolved = resolve_panel_order(None, || names(&["Project Panel", "GitPanel"]));
+        assert_eq!(resolved, names(&["Project Panel", "GitPanel"]));
+    }
+
+    #[test]
+    fn panel_group_and_index_finds_top_panel() {
+        let top = names(&["Project Panel", "GitPanel"]);
+        let bottom = names(&["TerminalPanel"]);
+        assert_eq!(
+            panel_group_and_index("GitPanel", &top, &bottom),
+            Some((SidebarButtonGroup::Top, 1))
+        );
+    }
+
+    #[test]
+    fn panel_group_and_index_finds_bottom_panel() {
+        let top = names(&["Project Panel"]);
+        let bottom = names(&["TerminalPanel", "DebugPanel"]);
+        assert_eq!(
+            panel_group_and_index("DebugPanel", &top, &bottom),
+            Some((SidebarButtonGroup::Bottom, 1))
+        );
+    }
+
+    #[test]
+    fn panel_group_and_index_returns_none_for_unlisted_panel() {
+        let top = names(&["Project Panel"]);
+        let bottom = names(&["TerminalPanel"]);
+        assert_eq!(
+            panel_group_and_index("NotificationPanel", &top, &bottom),
+            None
+        );
+    }
+
+    #[test]
+    fn is_self_drop_detects_same_group_same_name() {
+        let name = SharedString::from("GitPanel");
+        assert!(is_self_drop(
+            SidebarButtonGroup::Top,
+            &name,
+            SidebarButtonGroup::Top,
+            &name,
+        ));
+    }
+
+    #[test]
+    fn is_self_drop_is_false_across_groups_or_names() {
+        let dragged = SharedString::from("GitPanel");
+        let other = SharedString::from("Project Panel");
+        assert!(!is_self_drop(
+            SidebarButtonGroup::Top,
+            &dragged,
+            SidebarButtonGroup::Bottom,
+            &dragged,
+        ));
+        assert!(!is_self_drop(
+            SidebarButtonGroup::Top,
+            &dragged,
+            SidebarButtonGroup::Top,
+            &other,
+        ));
+    }
+
+    #[test]
+    fn reorder_panel_names_moves_dragged_before_target() {
+        let order = names(&["Project Panel", "GitPanel", "Outline Panel"]);
+        let reordered = reorder_panel_names(
+            order,
+            &SharedString::from("Outline Panel"),
+            &SharedString::from("Project Panel"),
+        );
+        assert_eq!(
+            reordered,
+            names(&["Outline Panel", "Project Panel", "GitPanel"])
+        );
+    }
+
+    #[test]
+    fn reorder_panel_names_dropped_on_self_moves_to_end() {
+        // Without the `is_self_drop` guard in `handle_panel_button_drop`, calling
+        // `reorder_panel_names` with the dragged name as its own target removes it
+        // and then can't find a match to reinsert at, so it falls back to the end
+        // of the list — silently reordering a no-op drag. This is exactly the bug
+        // the caller's `is_self_drop` check exists to prevent.
+        let order = names(&["Project Panel", "GitPanel", "Outline Panel"]);
+        let dragged = SharedString::from("Project Panel");
+        let reordered = reorder_panel_names(order, &dragged, &dragged);
+        assert_eq!(
+            reordered,
+            names(&["GitPanel", "Outline Panel", "Project Panel"])
+        );
+    }
+
+    #[test]
+    fn move_panel_to_group_end_relocates_name() {
+        let source = names(&["Project Panel", "GitPanel"]);
+        let target = names(&["TerminalPanel"]);
+        let (source, target) =
+            move_panel_to_group_end(source, target, &SharedString::from("GitPanel"));
+        assert_eq!(source, names(&["Project Panel"]));
+        assert_eq!(target, names(&["TerminalPanel", "GitPanel"]));
+    }
+}